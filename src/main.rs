@@ -1,14 +1,32 @@
 use aws_config::meta::region::RegionProviderChain;
 use aws_config::BehaviorVersion;
 use aws_sdk_s3::config::Region;
+use aws_sdk_s3::presigning::PresigningConfig;
+use aws_sdk_s3::primitives::ByteStream;
+use aws_sdk_s3::types::{
+    CompletedMultipartUpload, CompletedPart, Delete, ObjectIdentifier, Tag, Tagging,
+};
 use aws_sdk_s3::Client;
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::env;
 use std::error::Error;
 use std::fs::File;
 use std::io::Read;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::AsyncReadExt;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// Maximum number of object parts uploaded concurrently during a multipart upload.
+const UPLOAD_CONCURRENCY: usize = 8;
+
+/// Largest object that can be copied in a single `copy_object` call (5 GiB);
+/// larger objects must be copied part by part with `upload_part_copy`.
+const MAX_SINGLE_COPY_SIZE: i64 = 5 * 1024 * 1024 * 1024;
 
 #[derive(Parser)]
 #[command(
@@ -34,18 +52,30 @@ enum Commands {
     Ls {
         #[arg(help = "Name of the bucket to list objects from (optional)")]
         bucket: Option<String>,
+        #[arg(long, help = "Only list objects whose key starts with this prefix")]
+        prefix: Option<String>,
+        #[arg(
+            long,
+            help = "Group keys sharing a common prefix up to this delimiter (e.g. '/')"
+        )]
+        delimiter: Option<String>,
+        #[arg(
+            long = "max-keys",
+            alias = "limit",
+            help = "Stop after listing at most this many objects"
+        )]
+        max_keys: Option<i32>,
     },
     #[command(
-        about = "Move/rename objects within a bucket",
-        long_about = "Move or rename objects within the same bucket using source and destination paths\n\
-        Example: r2 mv my-bucket file1.txt folder/file2.txt"
+        about = "Move/rename objects, optionally across buckets",
+        long_about = "Server-side copy an object to a new location and delete the original. Source and \
+        destination are each given as 'bucket/key', so objects can be moved within or across buckets\n\
+        Example: r2 mv my-bucket/file1.txt other-bucket/folder/file2.txt"
     )]
     Mv {
-        #[arg(help = "Name of the bucket containing the object")]
-        bucket: String,
-        #[arg(help = "Source object key (path to existing object)")]
+        #[arg(help = "Source object in format 'bucket/key'")]
         src: String,
-        #[arg(help = "Destination object key (new path/name)")]
+        #[arg(help = "Destination object in format 'bucket/key'")]
         dst: String,
     },
     #[command(
@@ -60,6 +90,29 @@ enum Commands {
             help = "Destination path in format 'bucket/key' (e.g., 'my-bucket/folder/file.txt')"
         )]
         dst: String,
+        #[arg(
+            long,
+            default_value_t = 64 * 1024 * 1024,
+            help = "A single file larger than this many bytes is uploaded with multipart upload; --recursive uploads each file in one request"
+        )]
+        multipart_threshold: u64,
+        #[arg(
+            long,
+            default_value_t = 16 * 1024 * 1024,
+            help = "Size in bytes of each part during a multipart upload"
+        )]
+        part_size: u64,
+        #[arg(
+            short,
+            long,
+            help = "Recursively transfer a local directory to a prefix, or a prefix back to a local directory"
+        )]
+        recursive: bool,
+        #[arg(
+            long,
+            help = "Treat src as an R2 'bucket/key' and copy it server-side instead of uploading a local file"
+        )]
+        server_side: bool,
     },
     #[command(
         about = "Delete an object from a bucket",
@@ -72,6 +125,129 @@ enum Commands {
         #[arg(help = "Object key to delete")]
         key: String,
     },
+    #[command(
+        about = "Create a bucket",
+        long_about = "Create a new bucket\n\
+        Example: r2 mb my-bucket"
+    )]
+    Mb {
+        #[arg(help = "Name of the bucket to create")]
+        bucket: String,
+    },
+    #[command(
+        about = "Delete a bucket",
+        long_about = "Delete a bucket. Pass --force to first empty the bucket of all objects\n\
+        Example: r2 rb my-bucket --force"
+    )]
+    Rb {
+        #[arg(help = "Name of the bucket to delete")]
+        bucket: String,
+        #[arg(short, long, help = "Empty the bucket before deleting it")]
+        force: bool,
+    },
+    #[command(
+        alias = "head",
+        about = "Check whether a bucket exists",
+        long_about = "Check whether a bucket exists, exiting with a non-zero status when it does not \
+        so the result can be used in scripts\n\
+        Example: r2 exists my-bucket"
+    )]
+    Exists {
+        #[arg(help = "Name of the bucket to check")]
+        bucket: String,
+    },
+    #[command(
+        about = "Synchronize a local directory and a bucket prefix",
+        long_about = "Recursively transfer only the objects whose size differs between source and \
+        destination. The source may be a local directory (upload) or a 'bucket/prefix' (download)\n\
+        Example: r2 sync ./site my-bucket/site"
+    )]
+    Sync {
+        #[arg(help = "Source: a local directory or a 'bucket/prefix'")]
+        src: String,
+        #[arg(help = "Destination: a 'bucket/prefix' or a local directory")]
+        dst: String,
+    },
+    #[command(
+        about = "Generate a presigned URL for an object",
+        long_about = "Generate a time-limited presigned URL for downloading (GET) or uploading (PUT) an object, \
+        so it can be shared without distributing credentials\n\
+        Example: r2 presign my-bucket file.png --method get --expires-in 3600"
+    )]
+    Presign {
+        #[arg(help = "Name of the bucket containing the object")]
+        bucket: String,
+        #[arg(help = "Object key to presign")]
+        key: String,
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = PresignMethod::Get,
+            help = "HTTP method the URL is valid for"
+        )]
+        method: PresignMethod,
+        #[arg(
+            long,
+            default_value_t = 3600,
+            help = "Lifetime of the URL in seconds"
+        )]
+        expires_in: u64,
+        #[arg(
+            long,
+            help = "Override the Content-Disposition response header (GET only), e.g. 'attachment; filename=\"x.png\"'"
+        )]
+        response_content_disposition: Option<String>,
+        #[arg(
+            long,
+            help = "Override the Content-Type response header (GET only)"
+        )]
+        response_content_type: Option<String>,
+    },
+    #[command(
+        about = "Manage object tags",
+        long_about = "Get, set, or remove the tags attached to an object\n\
+        Examples:\n\
+        - Set tags: r2 tag set my-bucket file.txt env=prod team=infra\n\
+        - Get tags: r2 tag get my-bucket file.txt\n\
+        - Remove tags: r2 tag rm my-bucket file.txt"
+    )]
+    Tag {
+        #[command(subcommand)]
+        action: TagCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum TagCommand {
+    #[command(about = "Replace the tags on an object with the given key=value pairs")]
+    Set {
+        #[arg(help = "Name of the bucket containing the object")]
+        bucket: String,
+        #[arg(help = "Object key to tag")]
+        key: String,
+        #[arg(help = "Tags as 'key=value' pairs", required = true)]
+        tags: Vec<String>,
+    },
+    #[command(about = "Print the tags on an object")]
+    Get {
+        #[arg(help = "Name of the bucket containing the object")]
+        bucket: String,
+        #[arg(help = "Object key to read tags from")]
+        key: String,
+    },
+    #[command(about = "Remove all tags from an object")]
+    Rm {
+        #[arg(help = "Name of the bucket containing the object")]
+        bucket: String,
+        #[arg(help = "Object key to remove tags from")]
+        key: String,
+    },
+}
+
+#[derive(Clone, ValueEnum)]
+enum PresignMethod {
+    Get,
+    Put,
 }
 
 #[derive(Deserialize)]
@@ -91,6 +267,427 @@ struct Config {
     metadata: Metadata,
 }
 
+/// Upload a local file as a multipart upload, streaming it from disk in
+/// fixed-size parts. Parts are uploaded with bounded concurrency; on any
+/// failure the upload is aborted so no orphaned parts remain billable.
+async fn multipart_upload(
+    client: &Client,
+    bucket: &str,
+    key: &str,
+    path: &str,
+    part_size: u64,
+) -> Result<(), Box<dyn Error>> {
+    let create = client
+        .create_multipart_upload()
+        .bucket(bucket)
+        .key(key)
+        .send()
+        .await?;
+    let upload_id = create
+        .upload_id()
+        .ok_or("create_multipart_upload returned no upload id")?
+        .to_string();
+
+    match upload_parts(client, bucket, key, &upload_id, path, part_size).await {
+        Ok(mut parts) => {
+            parts.sort_by_key(|part| part.part_number().unwrap_or_default());
+            let completed = CompletedMultipartUpload::builder()
+                .set_parts(Some(parts))
+                .build();
+            if let Err(err) = client
+                .complete_multipart_upload()
+                .bucket(bucket)
+                .key(key)
+                .upload_id(&upload_id)
+                .multipart_upload(completed)
+                .send()
+                .await
+            {
+                client
+                    .abort_multipart_upload()
+                    .bucket(bucket)
+                    .key(key)
+                    .upload_id(&upload_id)
+                    .send()
+                    .await?;
+                return Err(err.into());
+            }
+            Ok(())
+        }
+        Err(err) => {
+            client
+                .abort_multipart_upload()
+                .bucket(bucket)
+                .key(key)
+                .upload_id(&upload_id)
+                .send()
+                .await?;
+            Err(err)
+        }
+    }
+}
+
+/// Read `path` in `part_size` chunks and upload each one, capping in-flight
+/// uploads with a semaphore. Returns the completed parts in arbitrary order.
+async fn upload_parts(
+    client: &Client,
+    bucket: &str,
+    key: &str,
+    upload_id: &str,
+    path: &str,
+    part_size: u64,
+) -> Result<Vec<CompletedPart>, Box<dyn Error>> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let semaphore = Arc::new(Semaphore::new(UPLOAD_CONCURRENCY));
+    let mut tasks = JoinSet::new();
+    let mut part_number = 1i32;
+
+    loop {
+        let mut buffer = vec![0u8; part_size as usize];
+        let mut filled = 0;
+        while filled < buffer.len() {
+            let read = file.read(&mut buffer[filled..]).await?;
+            if read == 0 {
+                break;
+            }
+            filled += read;
+        }
+        if filled == 0 {
+            break;
+        }
+        buffer.truncate(filled);
+
+        let permit = semaphore.clone().acquire_owned().await?;
+        let client = client.clone();
+        let bucket = bucket.to_string();
+        let key = key.to_string();
+        let upload_id = upload_id.to_string();
+        let this_part = part_number;
+        tasks.spawn(async move {
+            let _permit = permit;
+            let response = client
+                .upload_part()
+                .bucket(bucket)
+                .key(key)
+                .upload_id(upload_id)
+                .part_number(this_part)
+                .body(ByteStream::from(buffer))
+                .send()
+                .await?;
+            Ok::<_, Box<dyn Error + Send + Sync>>(
+                CompletedPart::builder()
+                    .set_e_tag(response.e_tag().map(String::from))
+                    .part_number(this_part)
+                    .build(),
+            )
+        });
+        part_number += 1;
+    }
+
+    let mut parts = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        parts.push(joined??);
+    }
+    Ok(parts)
+}
+
+/// Split a `bucket/key` argument into its two halves, erroring when the key is
+/// missing.
+fn split_bucket_key(target: &str) -> Result<(&str, &str), Box<dyn Error>> {
+    target
+        .split_once('/')
+        .filter(|(_, key)| !key.is_empty())
+        .ok_or_else(|| "expected a value in format bucket/key".into())
+}
+
+/// Percent-encode an object key into the `bucket/key` form expected by the
+/// `x-amz-copy-source` header, leaving path separators intact.
+fn encode_copy_source(bucket: &str, key: &str) -> String {
+    let mut encoded = String::from(bucket);
+    encoded.push('/');
+    for byte in key.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                encoded.push(byte as char);
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Copy an object larger than the single-copy limit by ranged `upload_part_copy`
+/// calls wrapped in a multipart upload, aborting the upload on any failure.
+async fn multipart_copy(
+    client: &Client,
+    copy_source: &str,
+    bucket: &str,
+    key: &str,
+    size: i64,
+    part_size: u64,
+) -> Result<(), Box<dyn Error>> {
+    let create = client
+        .create_multipart_upload()
+        .bucket(bucket)
+        .key(key)
+        .send()
+        .await?;
+    let upload_id = create
+        .upload_id()
+        .ok_or("create_multipart_upload returned no upload id")?
+        .to_string();
+
+    match copy_parts(client, copy_source, bucket, key, &upload_id, size, part_size).await {
+        Ok(parts) => {
+            let completed = CompletedMultipartUpload::builder()
+                .set_parts(Some(parts))
+                .build();
+            if let Err(err) = client
+                .complete_multipart_upload()
+                .bucket(bucket)
+                .key(key)
+                .upload_id(&upload_id)
+                .multipart_upload(completed)
+                .send()
+                .await
+            {
+                client
+                    .abort_multipart_upload()
+                    .bucket(bucket)
+                    .key(key)
+                    .upload_id(&upload_id)
+                    .send()
+                    .await?;
+                return Err(err.into());
+            }
+            Ok(())
+        }
+        Err(err) => {
+            client
+                .abort_multipart_upload()
+                .bucket(bucket)
+                .key(key)
+                .upload_id(&upload_id)
+                .send()
+                .await?;
+            Err(err)
+        }
+    }
+}
+
+/// Issue the ranged `upload_part_copy` calls for [`multipart_copy`], returning
+/// the completed parts in order.
+async fn copy_parts(
+    client: &Client,
+    copy_source: &str,
+    bucket: &str,
+    key: &str,
+    upload_id: &str,
+    size: i64,
+    part_size: u64,
+) -> Result<Vec<CompletedPart>, Box<dyn Error>> {
+    let mut parts = Vec::new();
+    let mut part_number = 1i32;
+    let mut start = 0i64;
+
+    while start < size {
+        let end = (start + part_size as i64 - 1).min(size - 1);
+        let response = client
+            .upload_part_copy()
+            .bucket(bucket)
+            .key(key)
+            .upload_id(upload_id)
+            .copy_source(copy_source)
+            .copy_source_range(format!("bytes={}-{}", start, end))
+            .part_number(part_number)
+            .send()
+            .await?;
+        let e_tag = response
+            .copy_part_result()
+            .and_then(|result| result.e_tag())
+            .map(String::from);
+        parts.push(
+            CompletedPart::builder()
+                .set_e_tag(e_tag)
+                .part_number(part_number)
+                .build(),
+        );
+        part_number += 1;
+        start = end + 1;
+    }
+
+    Ok(parts)
+}
+
+/// Split a `bucket/prefix` argument into its bucket and prefix halves. A bare
+/// `bucket` with no slash yields an empty prefix (the whole bucket).
+fn split_prefix(target: &str) -> (&str, &str) {
+    match target.split_once('/') {
+        Some((bucket, prefix)) => (bucket, prefix),
+        None => (target, ""),
+    }
+}
+
+/// Build an object key from a prefix and a relative path, collapsing any
+/// trailing slash on the prefix.
+fn join_key(prefix: &str, rel: &str) -> String {
+    let prefix = prefix.trim_end_matches('/');
+    if prefix.is_empty() {
+        rel.to_string()
+    } else {
+        format!("{}/{}", prefix, rel)
+    }
+}
+
+/// Recursively collect the regular files beneath `root`.
+fn collect_local_files(root: &Path, out: &mut Vec<PathBuf>) -> Result<(), Box<dyn Error>> {
+    for entry in std::fs::read_dir(root)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_local_files(&path, out)?;
+        } else {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// List every object under `prefix`, following continuation tokens, returning
+/// each key paired with its size.
+async fn collect_objects(
+    client: &Client,
+    bucket: &str,
+    prefix: &str,
+) -> Result<Vec<(String, i64)>, Box<dyn Error>> {
+    let mut objects = Vec::new();
+    let mut continuation_token: Option<String> = None;
+    loop {
+        let mut request = client.list_objects_v2().bucket(bucket);
+        if !prefix.is_empty() {
+            request = request.prefix(prefix);
+        }
+        if let Some(token) = &continuation_token {
+            request = request.continuation_token(token);
+        }
+        let response = request.send().await?;
+
+        for object in response.contents() {
+            objects.push((
+                object.key().unwrap_or_default().to_string(),
+                object.size().unwrap_or_default(),
+            ));
+        }
+
+        if response.is_truncated().unwrap_or(false) {
+            continuation_token = response.next_continuation_token().map(String::from);
+            if continuation_token.is_none() {
+                break;
+            }
+        } else {
+            break;
+        }
+    }
+    Ok(objects)
+}
+
+/// Upload every file under `dir` to `bucket` under `prefix`, preserving the
+/// relative directory structure as key suffixes. When `sync` is set, files
+/// whose size already matches the remote object are skipped.
+async fn recursive_upload(
+    client: &Client,
+    dir: &str,
+    bucket: &str,
+    prefix: &str,
+    sync: bool,
+) -> Result<(), Box<dyn Error>> {
+    let root = Path::new(dir);
+    let mut files = Vec::new();
+    collect_local_files(root, &mut files)?;
+
+    let remote: HashMap<String, i64> = if sync {
+        collect_objects(client, bucket, prefix)
+            .await?
+            .into_iter()
+            .collect()
+    } else {
+        HashMap::new()
+    };
+
+    let semaphore = Arc::new(Semaphore::new(UPLOAD_CONCURRENCY));
+    let mut tasks = JoinSet::new();
+
+    for file in files {
+        let rel = file.strip_prefix(root)?.to_string_lossy().replace('\\', "/");
+        let key = join_key(prefix, &rel);
+
+        if sync {
+            let size = std::fs::metadata(&file)?.len() as i64;
+            if remote.get(&key) == Some(&size) {
+                continue;
+            }
+        }
+
+        let permit = semaphore.clone().acquire_owned().await?;
+        let client = client.clone();
+        let bucket = bucket.to_string();
+        tasks.spawn(async move {
+            let _permit = permit;
+            let body = ByteStream::from_path(&file).await?;
+            client
+                .put_object()
+                .bucket(bucket)
+                .key(key)
+                .body(body)
+                .send()
+                .await?;
+            Ok::<_, Box<dyn Error + Send + Sync>>(())
+        });
+    }
+
+    while let Some(joined) = tasks.join_next().await {
+        joined??;
+    }
+    Ok(())
+}
+
+/// Download every object under `prefix` into `dir`, recreating the directory
+/// structure locally. When `sync` is set, objects whose size already matches
+/// the local file are skipped.
+async fn recursive_download(
+    client: &Client,
+    bucket: &str,
+    prefix: &str,
+    dir: &str,
+    sync: bool,
+) -> Result<(), Box<dyn Error>> {
+    let root = Path::new(dir);
+    for (key, size) in collect_objects(client, bucket, prefix).await? {
+        let rel = key.strip_prefix(prefix).unwrap_or(&key).trim_start_matches('/');
+        if rel.is_empty() {
+            continue;
+        }
+        let dest = root.join(rel);
+
+        if sync {
+            if let Ok(metadata) = std::fs::metadata(&dest) {
+                if metadata.len() as i64 == size {
+                    continue;
+                }
+            }
+        }
+
+        if let Some(parent) = dest.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        let object = client.get_object().bucket(bucket).key(&key).send().await?;
+        let mut reader = object.body.into_async_read();
+        let mut file = tokio::fs::File::create(&dest).await?;
+        tokio::io::copy(&mut reader, &mut file).await?;
+    }
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
     let args = Args::parse();
@@ -137,7 +734,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let client = Client::new(&config);
 
     match args.command {
-        None | Some(Commands::Ls { bucket: None }) => {
+        None | Some(Commands::Ls { bucket: None, .. }) => {
             let buckets = client.list_buckets().send().await?;
             for bucket in buckets.buckets() {
                 println!("{}", bucket.name().unwrap_or_default());
@@ -145,43 +742,141 @@ async fn main() -> Result<(), Box<dyn Error>> {
         }
         Some(Commands::Ls {
             bucket: Some(bucket),
+            prefix,
+            delimiter,
+            max_keys,
         }) => {
-            let objects = client.list_objects_v2().bucket(bucket).send().await?;
-            for object in objects.contents() {
-                println!("{}", object.key().unwrap_or_default());
+            let mut continuation_token: Option<String> = None;
+            let mut printed = 0i32;
+            loop {
+                let mut request = client.list_objects_v2().bucket(&bucket);
+                if let Some(prefix) = &prefix {
+                    request = request.prefix(prefix);
+                }
+                if let Some(delimiter) = &delimiter {
+                    request = request.delimiter(delimiter);
+                }
+                if let Some(token) = &continuation_token {
+                    request = request.continuation_token(token);
+                }
+                if let Some(max) = max_keys {
+                    request = request.max_keys((max - printed).min(1000));
+                }
+                let response = request.send().await?;
+
+                for common_prefix in response.common_prefixes() {
+                    println!("{}", common_prefix.prefix().unwrap_or_default());
+                }
+                for object in response.contents() {
+                    println!("{}", object.key().unwrap_or_default());
+                    printed += 1;
+                    if let Some(max) = max_keys {
+                        if printed >= max {
+                            return Ok(());
+                        }
+                    }
+                }
+
+                if response.is_truncated().unwrap_or(false) {
+                    continuation_token = response.next_continuation_token().map(String::from);
+                    if continuation_token.is_none() {
+                        break;
+                    }
+                } else {
+                    break;
+                }
             }
         }
-        Some(Commands::Mv { bucket, src, dst }) => {
+        Some(Commands::Mv { src, dst }) => {
+            let (src_bucket, src_key) = split_bucket_key(&src)?;
+            let (dst_bucket, dst_key) = split_bucket_key(&dst)?;
+
             client
                 .copy_object()
-                .bucket(&bucket)
-                .copy_source(format!("{}/{}", bucket, src))
-                .key(&dst)
+                .bucket(dst_bucket)
+                .copy_source(encode_copy_source(src_bucket, src_key))
+                .key(dst_key)
                 .send()
                 .await?;
 
             client
                 .delete_object()
-                .bucket(&bucket)
-                .key(&src)
+                .bucket(src_bucket)
+                .key(src_key)
                 .send()
                 .await?;
         }
-        Some(Commands::Cp { src, dst }) => {
-            let parts: Vec<&str> = dst.splitn(2, '/').collect();
-            if parts.len() != 2 {
-                return Err("Destination must be in format bucket/key".into());
-            }
-            let (bucket, key) = (parts[0], parts[1]);
+        Some(Commands::Cp {
+            src,
+            dst,
+            multipart_threshold,
+            part_size,
+            recursive,
+            server_side,
+        }) => {
+            if server_side {
+                let (src_bucket, src_key) = split_bucket_key(&src)?;
+                let (dst_bucket, dst_key) = split_bucket_key(&dst)?;
+                let copy_source = encode_copy_source(src_bucket, src_key);
 
-            let body = tokio::fs::read(src).await?;
-            client
-                .put_object()
-                .bucket(bucket)
-                .key(key)
-                .body(body.into())
-                .send()
-                .await?;
+                let head = client
+                    .head_object()
+                    .bucket(src_bucket)
+                    .key(src_key)
+                    .send()
+                    .await?;
+                let size = head.content_length().unwrap_or_default();
+
+                if size > MAX_SINGLE_COPY_SIZE {
+                    multipart_copy(&client, &copy_source, dst_bucket, dst_key, size, part_size)
+                        .await?;
+                } else {
+                    client
+                        .copy_object()
+                        .bucket(dst_bucket)
+                        .key(dst_key)
+                        .copy_source(copy_source)
+                        .send()
+                        .await?;
+                }
+            } else if recursive {
+                if Path::new(&src).is_dir() {
+                    let (bucket, prefix) = split_prefix(&dst);
+                    recursive_upload(&client, &src, bucket, prefix, false).await?;
+                } else {
+                    let (bucket, prefix) = split_prefix(&src);
+                    recursive_download(&client, bucket, prefix, &dst, false).await?;
+                }
+            } else {
+                let parts: Vec<&str> = dst.splitn(2, '/').collect();
+                if parts.len() != 2 {
+                    return Err("Destination must be in format bucket/key".into());
+                }
+                let (bucket, key) = (parts[0], parts[1]);
+
+                let size = tokio::fs::metadata(&src).await?.len();
+                if size > multipart_threshold {
+                    multipart_upload(&client, bucket, key, &src, part_size).await?;
+                } else {
+                    let body = ByteStream::from_path(&src).await?;
+                    client
+                        .put_object()
+                        .bucket(bucket)
+                        .key(key)
+                        .body(body)
+                        .send()
+                        .await?;
+                }
+            }
+        }
+        Some(Commands::Sync { src, dst }) => {
+            if Path::new(&src).is_dir() {
+                let (bucket, prefix) = split_prefix(&dst);
+                recursive_upload(&client, &src, bucket, prefix, true).await?;
+            } else {
+                let (bucket, prefix) = split_prefix(&src);
+                recursive_download(&client, bucket, prefix, &dst, true).await?;
+            }
         }
         Some(Commands::Rm { bucket, key }) => {
             client
@@ -191,6 +886,103 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 .send()
                 .await?;
         }
+        Some(Commands::Presign {
+            bucket,
+            key,
+            method,
+            expires_in,
+            response_content_disposition,
+            response_content_type,
+        }) => {
+            let presigning = PresigningConfig::expires_in(Duration::from_secs(expires_in))?;
+
+            let uri = match method {
+                PresignMethod::Get => {
+                    let mut request = client.get_object().bucket(&bucket).key(&key);
+                    if let Some(disposition) = response_content_disposition {
+                        request = request.response_content_disposition(disposition);
+                    }
+                    if let Some(content_type) = response_content_type {
+                        request = request.response_content_type(content_type);
+                    }
+                    request.presigned(presigning).await?.uri().to_string()
+                }
+                PresignMethod::Put => client
+                    .put_object()
+                    .bucket(&bucket)
+                    .key(&key)
+                    .presigned(presigning)
+                    .await?
+                    .uri()
+                    .to_string(),
+            };
+
+            println!("{}", uri);
+        }
+        Some(Commands::Mb { bucket }) => {
+            client.create_bucket().bucket(bucket).send().await?;
+        }
+        Some(Commands::Rb { bucket, force }) => {
+            if force {
+                let objects = collect_objects(&client, &bucket, "").await?;
+                for chunk in objects.chunks(1000) {
+                    let mut delete = Delete::builder();
+                    for (key, _) in chunk {
+                        delete = delete.objects(ObjectIdentifier::builder().key(key).build()?);
+                    }
+                    client
+                        .delete_objects()
+                        .bucket(&bucket)
+                        .delete(delete.build()?)
+                        .send()
+                        .await?;
+                }
+            }
+            client.delete_bucket().bucket(&bucket).send().await?;
+        }
+        Some(Commands::Exists { bucket }) => {
+            if client.head_bucket().bucket(&bucket).send().await.is_err() {
+                std::process::exit(2);
+            }
+        }
+        Some(Commands::Tag { action }) => match action {
+            TagCommand::Set { bucket, key, tags } => {
+                let mut tag_set = Vec::with_capacity(tags.len());
+                for pair in &tags {
+                    let (k, v) = pair
+                        .split_once('=')
+                        .ok_or("tags must be in key=value format")?;
+                    tag_set.push(Tag::builder().key(k).value(v).build()?);
+                }
+                let tagging = Tagging::builder().set_tag_set(Some(tag_set)).build()?;
+                client
+                    .put_object_tagging()
+                    .bucket(bucket)
+                    .key(key)
+                    .tagging(tagging)
+                    .send()
+                    .await?;
+            }
+            TagCommand::Get { bucket, key } => {
+                let response = client
+                    .get_object_tagging()
+                    .bucket(bucket)
+                    .key(key)
+                    .send()
+                    .await?;
+                for tag in response.tag_set() {
+                    println!("{}={}", tag.key(), tag.value());
+                }
+            }
+            TagCommand::Rm { bucket, key } => {
+                client
+                    .delete_object_tagging()
+                    .bucket(bucket)
+                    .key(key)
+                    .send()
+                    .await?;
+            }
+        },
     }
 
     Ok(())